@@ -12,6 +12,33 @@ fn calc_tabulation_size(a: f64, l: f64) -> usize {
     MIN_TABULATION_SIZE.max((2.0 * l / a * MIN_FRAMERATE + 1.0).ceil() as usize)
 }
 
+/// Builds a polyline through `points`, connecting samples with straight segments.
+fn push_polyline(path: &web_sys::Path2d, points: &[(f64, f64)]) {
+    let (x0, y0) = points[0];
+    path.move_to(x0, y0);
+    for &(x, y) in &points[1..] {
+        path.line_to(x, y);
+    }
+}
+
+/// Builds a C1-continuous interpolating spline through `points` by converting a
+/// Catmull-Rom spline to a sequence of cubic Béziers, clamping the missing
+/// neighbor at each end of the curve.
+fn push_smooth_path(path: &web_sys::Path2d, points: &[(f64, f64)]) {
+    let n = points.len();
+    let (x0, y0) = points[0];
+    path.move_to(x0, y0);
+    for i in 1..n {
+        let p1 = points[i - 1];
+        let p2 = points[i];
+        let p0 = if i >= 2 { points[i - 2] } else { p1 };
+        let p3 = if i + 1 < n { points[i + 1] } else { p2 };
+        let b1 = (p1.0 + (p2.0 - p0.0) / 6.0, p1.1 + (p2.1 - p0.1) / 6.0);
+        let b2 = (p2.0 - (p3.0 - p1.0) / 6.0, p2.1 - (p3.1 - p1.1) / 6.0);
+        path.bezier_curve_to(b1.0, b1.1, b2.0, b2.1, p2.0, p2.1);
+    }
+}
+
 #[wasm_bindgen]
 extern "C" {
     #[wasm_bindgen(typescript_type = "(arg: number) => number")]
@@ -30,17 +57,79 @@ impl RealFunction {
     }
 }
 
+/// Maps a sample's value in `[low_value, high_value]` to a color between
+/// `low_color` and `high_color`, both given as RGBA channels in `0..=255`.
+#[derive(Debug, Clone, Copy)]
+struct CurveGradient {
+    low_color: [f64; 4],
+    high_color: [f64; 4],
+    low_value: f64,
+    high_value: f64,
+}
+
+impl CurveGradient {
+    fn color_at(&self, value: f64) -> String {
+        let t = if self.high_value <= self.low_value {
+            0.0
+        } else {
+            ((value - self.low_value) / (self.high_value - self.low_value)).clamp(0.0, 1.0)
+        };
+        let lerp = |i: usize| self.low_color[i] + (self.high_color[i] - self.low_color[i]) * t;
+        format!(
+            "rgba({}, {}, {}, {})",
+            lerp(0) as u8,
+            lerp(1) as u8,
+            lerp(2) as u8,
+            lerp(3) / 255.0
+        )
+    }
+}
+
 #[wasm_bindgen]
 pub struct CurveView {
     visible: bool,
     color: JsValue,
+    gradient: Option<CurveGradient>,
 }
 
 #[wasm_bindgen]
 impl CurveView {
     #[wasm_bindgen(constructor)]
     pub fn new(visible: bool, color: JsValue) -> CurveView {
-        CurveView { visible, color }
+        CurveView {
+            visible,
+            color,
+            gradient: None,
+        }
+    }
+
+    /// Colors the curve by amplitude instead of a flat `color`, blending
+    /// between `low_color` and `high_color` (each RGBA channels in `0..=255`)
+    /// as the sample value ranges over `[low_value, high_value]`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn set_gradient(
+        &mut self,
+        low_r: f64,
+        low_g: f64,
+        low_b: f64,
+        low_a: f64,
+        high_r: f64,
+        high_g: f64,
+        high_b: f64,
+        high_a: f64,
+        low_value: f64,
+        high_value: f64,
+    ) {
+        self.gradient = Some(CurveGradient {
+            low_color: [low_r, low_g, low_b, low_a],
+            high_color: [high_r, high_g, high_b, high_a],
+            low_value,
+            high_value,
+        });
+    }
+
+    pub fn clear_gradient(&mut self) {
+        self.gradient = None;
     }
 }
 
@@ -71,6 +160,38 @@ struct UDiffPairPoint {
     u_x: f64,
 }
 
+/// A 2D affine camera over the (x, t) sample plane: `scale` zooms about the
+/// canvas center, `translate_x`/`translate_t` pan it in device pixels.
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy)]
+pub struct Viewport {
+    pub scale: f64,
+    pub translate_x: f64,
+    pub translate_t: f64,
+}
+
+impl Default for Viewport {
+    fn default() -> Self {
+        Viewport {
+            scale: 1.0,
+            translate_x: 0.0,
+            translate_t: 0.0,
+        }
+    }
+}
+
+#[wasm_bindgen]
+impl Viewport {
+    #[wasm_bindgen(constructor)]
+    pub fn new(scale: f64, translate_x: f64, translate_t: f64) -> Viewport {
+        Viewport {
+            scale,
+            translate_x,
+            translate_t,
+        }
+    }
+}
+
 #[wasm_bindgen]
 pub struct Renderer {
     left: UDiff,
@@ -81,6 +202,7 @@ pub struct Renderer {
     pub l: f64,
     rem_t: f64,
     cur_t: f64,
+    viewport: Viewport,
 }
 
 #[wasm_bindgen]
@@ -106,6 +228,7 @@ impl Renderer {
             floor,
             a,
             l,
+            viewport: Viewport::default(),
         }
     }
 
@@ -178,45 +301,167 @@ impl Renderer {
         }
     }
 
+    /// Reconstructs the displacement `u(x)` from the tabulated `u_x = du/dx`
+    /// samples via the cumulative trapezoidal rule, taking `u(0) = 0`.
+    fn reconstruct_u(&self) -> Vec<f64> {
+        let n = self.floor.len();
+        let dx = self.l / (n - 1) as f64;
+
+        let mut u = Vec::with_capacity(n);
+        u.push(0.0);
+        for i in 1..n {
+            let u_x_prev = self.floor[i - 1].get().u_x;
+            let u_x_cur = self.floor[i].get().u_x;
+            u.push(u[i - 1] + (u_x_prev + u_x_cur) / 2.0 * dx);
+        }
+        u
+    }
+
     pub fn render_canvas(
         &self,
         ctx: &web_sys::CanvasRenderingContext2d,
         u_view: CurveView,
         u_x_view: CurveView,
         u_t_view: CurveView,
+        smooth: bool,
     ) -> Result<(), JsValue> {
+        let canvas = ctx
+            .canvas()
+            .ok_or_else(|| JsValue::from_str("render_canvas: context has no bound canvas"))?;
+        let width = canvas.width() as f64;
+        let height = canvas.height() as f64;
+
+        ctx.clear_rect(0.0, 0.0, width, height);
+
+        let n = self.floor.len();
+
+        let Viewport {
+            scale,
+            translate_x,
+            translate_t,
+        } = self.viewport;
+
+        let x_from_idx = |i| {
+            (width * i as f64 / (n - 1) as f64 - width / 2.0) * scale + width / 2.0 + translate_x
+        };
+        let t_y =
+            |y| (height * (0.5 - y / self.l) - height / 2.0) * scale + height / 2.0 + translate_t;
+
+        let u_x_values: Vec<_> = self.floor.iter().map(|p| p.get().u_x).collect();
+        let u_t_values: Vec<_> = self.floor.iter().map(|p| p.get().u_t).collect();
+        let u_values = self.reconstruct_u();
+
+        let to_points = |values: &[f64]| -> Vec<_> {
+            values
+                .iter()
+                .enumerate()
+                .map(|(i, &y)| (x_from_idx(i), t_y(y)))
+                .collect()
+        };
+
+        self.draw_curve(ctx, &u_view, &to_points(&u_values), &u_values, smooth)?;
+        self.draw_curve(ctx, &u_x_view, &to_points(&u_x_values), &u_x_values, smooth)?;
+        self.draw_curve(ctx, &u_t_view, &to_points(&u_t_values), &u_t_values, smooth)?;
+
+        Ok(())
+    }
+
+    /// Strokes a single curve according to `view`: a flat `Path2d` in
+    /// `view.color`, or, when `view.gradient` is set, a series of short
+    /// per-segment strokes colored by each segment's average `values`.
+    fn draw_curve(
+        &self,
+        ctx: &web_sys::CanvasRenderingContext2d,
+        view: &CurveView,
+        points: &[(f64, f64)],
+        values: &[f64],
+        smooth: bool,
+    ) -> Result<(), JsValue> {
+        if !view.visible {
+            return Ok(());
+        }
+
+        match &view.gradient {
+            None => {
+                let path = web_sys::Path2d::new()?;
+                if smooth {
+                    push_smooth_path(&path, points);
+                } else {
+                    push_polyline(&path, points);
+                }
+                ctx.set_stroke_style(&view.color);
+                ctx.stroke_with_path(&path);
+            }
+            Some(gradient) => {
+                for i in 1..points.len() {
+                    let segment = web_sys::Path2d::new()?;
+                    segment.move_to(points[i - 1].0, points[i - 1].1);
+                    segment.line_to(points[i].0, points[i].1);
+                    let avg_value = (values[i - 1] + values[i]) / 2.0;
+                    ctx.set_stroke_style(&JsValue::from_str(&gradient.color_at(avg_value)));
+                    ctx.stroke_with_path(&segment);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn export_svg(&self, u_view: CurveView, u_x_view: CurveView, u_t_view: CurveView) -> String {
         const CANVAS_WIDTH: u32 = 480;
         const CANVAS_HEIGHT: u32 = 480;
 
-        ctx.clear_rect(0.0, 0.0, CANVAS_WIDTH as f64, CANVAS_HEIGHT as f64);
-
         let n = self.floor.len();
 
         let x_from_idx = |i| CANVAS_WIDTH as f64 * i as f64 / (n - 1) as f64;
         let t_y = |y| CANVAS_HEIGHT as f64 * (0.5 - y / self.l);
 
-        let u_x_path = web_sys::Path2d::new()?;
-        let u_t_path = web_sys::Path2d::new()?;
-
-        let init_point = self.floor.first().unwrap();
-        u_x_path.move_to(0.0, t_y(init_point.get().u_x));
-        u_t_path.move_to(0.0, t_y(init_point.get().u_t));
+        let path_d_values = |values: &[f64]| {
+            let mut d = format!("M 0 {}", t_y(values[0]));
+            for (i, &y) in values.iter().enumerate().skip(1) {
+                d.push_str(&format!(" L {} {}", x_from_idx(i), t_y(y)));
+            }
+            d
+        };
 
-        self.floor.iter().enumerate().skip(1).for_each(|(i, p)| {
-            u_x_path.line_to(x_from_idx(i), t_y(p.get().u_x));
-            u_t_path.line_to(x_from_idx(i), t_y(p.get().u_t));
-        });
+        let mut svg = format!(
+            r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 {CANVAS_WIDTH} {CANVAS_HEIGHT}">"#
+        );
 
+        if u_view.visible {
+            let d = path_d_values(&self.reconstruct_u());
+            let color = u_view.color.as_string().unwrap_or_default();
+            svg.push_str(&format!(r#"<path d="{d}" fill="none" stroke="{color}"/>"#));
+        }
         if u_x_view.visible {
-            ctx.set_stroke_style(&u_x_view.color);
-            ctx.stroke_with_path(&u_x_path);
+            let values: Vec<_> = self.floor.iter().map(|p| p.get().u_x).collect();
+            let d = path_d_values(&values);
+            let color = u_x_view.color.as_string().unwrap_or_default();
+            svg.push_str(&format!(r#"<path d="{d}" fill="none" stroke="{color}"/>"#));
         }
         if u_t_view.visible {
-            ctx.set_stroke_style(&u_t_view.color);
-            ctx.stroke_with_path(&u_t_path);
+            let values: Vec<_> = self.floor.iter().map(|p| p.get().u_t).collect();
+            let d = path_d_values(&values);
+            let color = u_t_view.color.as_string().unwrap_or_default();
+            svg.push_str(&format!(r#"<path d="{d}" fill="none" stroke="{color}"/>"#));
         }
 
-        Ok(())
+        svg.push_str("</svg>");
+        svg
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn viewport(&self) -> Viewport {
+        self.viewport
+    }
+
+    #[wasm_bindgen(setter)]
+    pub fn set_viewport(&mut self, viewport: Viewport) {
+        self.viewport = viewport;
+    }
+
+    pub fn reset_view(&mut self) {
+        self.viewport = Viewport::default();
     }
 
     #[wasm_bindgen(setter)]